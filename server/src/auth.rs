@@ -0,0 +1,231 @@
+// src/auth.rs
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use async_trait::async_trait;
+use axum::{extract::FromRequestParts, http::request::Parts};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+use crate::{error::Error, AppState};
+
+const TOKEN_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: Uuid,
+    pub exp: usize,
+}
+
+/// Hashes a plaintext password with Argon2 using a fresh random salt,
+/// returning the PHC string to store alongside the user row.
+pub fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default().hash_password(password.as_bytes(), &salt)?;
+    Ok(hash.to_string())
+}
+
+/// Verifies a plaintext password against a stored PHC hash.
+pub fn verify_password(hash: &str, password: &str) -> bool {
+    let Ok(parsed_hash) = PasswordHash::new(hash) else {
+        return false;
+    };
+    Argon2::default()
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .is_ok()
+}
+
+/// Signs a short-lived JWT carrying the user's id as `sub`.
+pub fn encode_token(user_id: Uuid, secret: &str) -> Result<String, jsonwebtoken::errors::Error> {
+    let exp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is before the unix epoch")
+        .checked_add(TOKEN_TTL)
+        .expect("token expiry overflowed")
+        .as_secs() as usize;
+    let claims = Claims { sub: user_id, exp };
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+}
+
+/// Extracts and validates a bearer JWT from the `Authorization` header,
+/// rejecting the request with 401 if it's missing, malformed, or expired.
+pub struct AuthUser {
+    pub user_id: Uuid,
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for AuthUser {
+    type Rejection = Error;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let header = parts
+            .headers
+            .get(axum::http::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(Error::Unauthorized)?;
+
+        let token = header.strip_prefix("Bearer ").ok_or(Error::Unauthorized)?;
+
+        let data = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(state.config.jwt_secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map_err(|_| Error::Unauthorized)?;
+
+        Ok(AuthUser {
+            user_id: data.claims.sub,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use axum::http::Request;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    const SECRET: &str = "test-secret";
+
+    #[test]
+    fn hash_and_verify_password_round_trip() {
+        let hash = hash_password("correct horse battery staple").unwrap();
+        assert!(verify_password(&hash, "correct horse battery staple"));
+        assert!(!verify_password(&hash, "wrong password"));
+    }
+
+    #[test]
+    fn hash_password_uses_a_fresh_salt_each_time() {
+        let a = hash_password("same-password").unwrap();
+        let b = hash_password("same-password").unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn encode_and_decode_token_round_trip() {
+        let user_id = Uuid::new_v4();
+        let token = encode_token(user_id, SECRET).unwrap();
+
+        let data = decode::<Claims>(
+            &token,
+            &DecodingKey::from_secret(SECRET.as_bytes()),
+            &Validation::default(),
+        )
+        .unwrap();
+
+        assert_eq!(data.claims.sub, user_id);
+    }
+
+    #[test]
+    fn decode_token_fails_with_wrong_secret() {
+        let token = encode_token(Uuid::new_v4(), SECRET).unwrap();
+
+        let result = decode::<Claims>(
+            &token,
+            &DecodingKey::from_secret(b"a-different-secret"),
+            &Validation::default(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    async fn test_app_state() -> AppState {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        AppState {
+            pool,
+            config: Config {
+                database_url: String::new(),
+                bind_addr: String::new(),
+                jwt_secret: SECRET.to_string(),
+                cors_allowed_origins: Vec::new(),
+                cors_allowed_methods: Vec::new(),
+                cors_allowed_headers: Vec::new(),
+            },
+        }
+    }
+
+    fn parts_with_authorization(value: Option<&str>) -> Parts {
+        let mut builder = Request::builder();
+        if let Some(value) = value {
+            builder = builder.header(axum::http::header::AUTHORIZATION, value);
+        }
+        let (parts, _) = builder.body(()).unwrap().into_parts();
+        parts
+    }
+
+    #[tokio::test]
+    async fn auth_user_rejects_missing_header() {
+        let state = test_app_state().await;
+        let mut parts = parts_with_authorization(None);
+
+        let result = AuthUser::from_request_parts(&mut parts, &state).await;
+        assert!(matches!(result, Err(Error::Unauthorized)));
+    }
+
+    #[tokio::test]
+    async fn auth_user_rejects_non_bearer_header() {
+        let state = test_app_state().await;
+        let mut parts = parts_with_authorization(Some("not-a-bearer-token"));
+
+        let result = AuthUser::from_request_parts(&mut parts, &state).await;
+        assert!(matches!(result, Err(Error::Unauthorized)));
+    }
+
+    #[tokio::test]
+    async fn auth_user_rejects_garbage_token() {
+        let state = test_app_state().await;
+        let mut parts = parts_with_authorization(Some("Bearer not-a-real-jwt"));
+
+        let result = AuthUser::from_request_parts(&mut parts, &state).await;
+        assert!(matches!(result, Err(Error::Unauthorized)));
+    }
+
+    #[tokio::test]
+    async fn auth_user_rejects_expired_token() {
+        let state = test_app_state().await;
+        let expired_claims = Claims {
+            sub: Uuid::new_v4(),
+            exp: 0,
+        };
+        let token = encode(
+            &Header::default(),
+            &expired_claims,
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap();
+        let mut parts = parts_with_authorization(Some(&format!("Bearer {token}")));
+
+        let result = AuthUser::from_request_parts(&mut parts, &state).await;
+        assert!(matches!(result, Err(Error::Unauthorized)));
+    }
+
+    #[tokio::test]
+    async fn auth_user_accepts_a_valid_token() {
+        let state = test_app_state().await;
+        let user_id = Uuid::new_v4();
+        let token = encode_token(user_id, SECRET).unwrap();
+        let mut parts = parts_with_authorization(Some(&format!("Bearer {token}")));
+
+        let auth = AuthUser::from_request_parts(&mut parts, &state)
+            .await
+            .unwrap();
+        assert_eq!(auth.user_id, user_id);
+    }
+}
@@ -0,0 +1,54 @@
+// src/config.rs
+/// Application configuration sourced from the environment.
+#[derive(Clone)]
+pub struct Config {
+    pub database_url: String,
+    pub bind_addr: String,
+    pub jwt_secret: String,
+    /// Origins allowed to make cross-origin requests. Empty means no
+    /// restriction has been configured, so callers fall back to a
+    /// permissive local/dev policy.
+    pub cors_allowed_origins: Vec<String>,
+    /// HTTP methods allowed for cross-origin requests. Only consulted
+    /// when `cors_allowed_origins` is non-empty.
+    pub cors_allowed_methods: Vec<String>,
+    /// Request headers allowed for cross-origin requests. Only
+    /// consulted when `cors_allowed_origins` is non-empty.
+    pub cors_allowed_headers: Vec<String>,
+}
+
+impl Config {
+    pub fn init() -> Self {
+        let database_url = std::env::var("DATABASE_URL")
+            .unwrap_or_else(|_| "sqlite://axum.db".to_string());
+        let bind_addr = std::env::var("BIND_ADDR")
+            .unwrap_or_else(|_| "127.0.0.1:3000".to_string());
+        let jwt_secret = std::env::var("JWT_SECRET")
+            .unwrap_or_else(|_| "dev-secret-change-me".to_string());
+        let cors_allowed_origins = parse_csv_env("CORS_ALLOWED_ORIGINS");
+        let cors_allowed_methods = parse_csv_env("CORS_ALLOWED_METHODS");
+        let cors_allowed_headers = parse_csv_env("CORS_ALLOWED_HEADERS");
+
+        Self {
+            database_url,
+            bind_addr,
+            jwt_secret,
+            cors_allowed_origins,
+            cors_allowed_methods,
+            cors_allowed_headers,
+        }
+    }
+}
+
+fn parse_csv_env(key: &str) -> Vec<String> {
+    std::env::var(key)
+        .map(|values| {
+            values
+                .split(',')
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
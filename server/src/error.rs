@@ -0,0 +1,48 @@
+// src/error.rs
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("resource not found")]
+    NotFound,
+
+    #[error("database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("validation error: {0}")]
+    Validation(String),
+
+    #[error("unauthorized")]
+    Unauthorized,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let (status, message) = match &self {
+            Error::NotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Error::Database(sqlx::Error::RowNotFound) => {
+                (StatusCode::NOT_FOUND, "resource not found".to_string())
+            }
+            Error::Database(sqlx::Error::Database(e)) if e.is_unique_violation() => (
+                StatusCode::CONFLICT,
+                "a resource with that value already exists".to_string(),
+            ),
+            Error::Database(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "internal server error".to_string(),
+            ),
+            Error::Validation(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            Error::Unauthorized => (StatusCode::UNAUTHORIZED, self.to_string()),
+        };
+
+        (status, Json(json!({ "error": message }))).into_response()
+    }
+}
@@ -1,61 +1,118 @@
 // src/handlers.rs
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     Json,
 };
-use crate::{models::{User, CreateUser, UpdateUser}, AppState};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use crate::{
+    auth::{encode_token, hash_password, verify_password, AuthUser},
+    error::{Error, Result},
+    models::{CreateUser, ListUsersQuery, LoginPayload, LoginResponse, UpdateUser, User, UserList},
+    AppState,
+};
+
+pub async fn healthcheck() -> Json<Value> {
+    Json(json!({ "status": "ok" }))
+}
+
+/// Escapes `%`, `_`, and the escape character itself so a user-supplied
+/// substring can be safely wrapped in a `LIKE ... ESCAPE '\'` pattern
+/// without its literal wildcard characters being interpreted as such.
+fn escape_like_pattern(pattern: &str) -> String {
+    pattern
+        .chars()
+        .flat_map(|c| match c {
+            '%' | '_' | '\\' => vec!['\\', c],
+            c => vec![c],
+        })
+        .collect()
+}
 
 pub async fn list_users(
     State(state): State<AppState>,
-) -> Result<Json<Vec<User>>, (StatusCode, String)> {
-    let users = sqlx::query_as::<_, User>("SELECT id, name, email FROM users ORDER BY id")
-        .fetch_all(&state.pool)
-        .await
-        .map_err(internal)?;
-    Ok(Json(users))
+    Query(params): Query<ListUsersQuery>,
+) -> Result<Json<UserList>> {
+    let limit = params.limit.unwrap_or(50).clamp(1, 200);
+    let offset = params.offset.unwrap_or(0).max(0);
+
+    let sort_column = match params.sort.as_deref() {
+        None | Some("id") => "id",
+        Some("name") => "name",
+        Some("email") => "email",
+        Some(other) => return Err(Error::Validation(format!("invalid sort column: {other}"))),
+    };
+
+    let where_clause = if params.email_contains.is_some() {
+        "WHERE email LIKE ? ESCAPE '\\'"
+    } else {
+        ""
+    };
+
+    let list_sql = format!(
+        "SELECT id, name, email, password_hash FROM users {where_clause} ORDER BY {sort_column} LIMIT ? OFFSET ?"
+    );
+    let count_sql = format!("SELECT COUNT(*) FROM users {where_clause}");
+
+    let mut list_query = sqlx::query_as::<_, User>(&list_sql);
+    let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+
+    if let Some(pattern) = &params.email_contains {
+        let like = format!("%{}%", escape_like_pattern(pattern));
+        list_query = list_query.bind(like.clone());
+        count_query = count_query.bind(like);
+    }
+    let list_query = list_query.bind(limit).bind(offset);
+
+    let items = list_query.fetch_all(&state.pool).await?;
+    let total = count_query.fetch_one(&state.pool).await?;
+
+    Ok(Json(UserList { items, total }))
 }
 
 pub async fn create_user(
     State(state): State<AppState>,
     Json(payload): Json<CreateUser>,
-) -> Result<Json<User>, (StatusCode, String)> {
-    let res = sqlx::query("INSERT INTO users (name, email) VALUES (?, ?)")
+) -> Result<Json<User>> {
+    let password_hash = hash_password(&payload.password)
+        .map_err(|e| Error::Validation(e.to_string()))?;
+    let id = Uuid::new_v4();
+
+    sqlx::query("INSERT INTO users (id, name, email, password_hash) VALUES (?, ?, ?, ?)")
+        .bind(id)
         .bind(&payload.name)
         .bind(&payload.email)
+        .bind(&password_hash)
         .execute(&state.pool)
-        .await
-        .map_err(internal)?;
-    let id = res.last_insert_rowid();
+        .await?;
 
-    let user = sqlx::query_as::<_, User>("SELECT id, name, email FROM users WHERE id = ?")
-        .bind(id)
-        .fetch_one(&state.pool)
-        .await
-        .map_err(internal)?;
+    let user = sqlx::query_as::<_, User>(
+        "SELECT id, name, email, password_hash FROM users WHERE id = ?",
+    )
+    .bind(id)
+    .fetch_one(&state.pool)
+    .await?;
     Ok(Json(user))
 }
 
-pub async fn get_user(
-    Path(id): Path<i64>,
-    State(state): State<AppState>,
-) -> Result<Json<User>, (StatusCode, String)> {
-    match sqlx::query_as::<_, User>("SELECT id, name, email FROM users WHERE id = ?")
+pub async fn get_user(Path(id): Path<Uuid>, State(state): State<AppState>) -> Result<Json<User>> {
+    let user = sqlx::query_as::<_, User>("SELECT id, name, email, password_hash FROM users WHERE id = ?")
         .bind(id)
         .fetch_one(&state.pool)
-        .await
-    {
-        Ok(user) => Ok(Json(user)),
-        Err(sqlx::Error::RowNotFound) => Err((StatusCode::NOT_FOUND, "User not found".into())),
-        Err(e) => Err(internal(e)),
-    }
+        .await?;
+    Ok(Json(user))
 }
 
 pub async fn update_user(
-    Path(id): Path<i64>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
     State(state): State<AppState>,
     Json(payload): Json<UpdateUser>,
-) -> Result<Json<User>, (StatusCode, String)> {
+) -> Result<Json<User>> {
+    tracing::debug!(actor = %auth.user_id, target = %id, "updating user");
+
     sqlx::query(
         "UPDATE users
          SET name = COALESCE(?, name),
@@ -66,34 +123,348 @@ pub async fn update_user(
     .bind(payload.email)
     .bind(id)
     .execute(&state.pool)
-    .await
-    .map_err(internal)?;
+    .await?;
 
-    let user = sqlx::query_as::<_, User>("SELECT id, name, email FROM users WHERE id = ?")
-        .bind(id)
-        .fetch_one(&state.pool)
-        .await
-        .map_err(internal)?;
+    let user = sqlx::query_as::<_, User>(
+        "SELECT id, name, email, password_hash FROM users WHERE id = ?",
+    )
+    .bind(id)
+    .fetch_one(&state.pool)
+    .await?;
     Ok(Json(user))
 }
 
 pub async fn delete_user(
-    Path(id): Path<i64>,
+    auth: AuthUser,
+    Path(id): Path<Uuid>,
     State(state): State<AppState>,
-) -> Result<StatusCode, (StatusCode, String)> {
+) -> Result<StatusCode> {
+    tracing::debug!(actor = %auth.user_id, target = %id, "deleting user");
+
     let rows = sqlx::query("DELETE FROM users WHERE id = ?")
         .bind(id)
         .execute(&state.pool)
-        .await
-        .map_err(internal)?
+        .await?
         .rows_affected();
 
     if rows == 0 {
-        return Err((StatusCode::NOT_FOUND, "User not found".into()));
+        return Err(Error::NotFound);
     }
     Ok(StatusCode::NO_CONTENT)
 }
 
-fn internal<E: std::fmt::Display>(e: E) -> (StatusCode, String) {
-    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+pub async fn login(
+    State(state): State<AppState>,
+    Json(payload): Json<LoginPayload>,
+) -> Result<Json<LoginResponse>> {
+    let user = sqlx::query_as::<_, User>(
+        "SELECT id, name, email, password_hash FROM users WHERE email = ?",
+    )
+    .bind(&payload.email)
+    .fetch_one(&state.pool)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::RowNotFound => Error::Unauthorized,
+        e => Error::Database(e),
+    })?;
+
+    if !verify_password(&user.password_hash, &payload.password) {
+        return Err(Error::Unauthorized);
+    }
+
+    let token = encode_token(user.id, &state.config.jwt_secret)
+        .map_err(|e| Error::Validation(e.to_string()))?;
+    Ok(Json(LoginResponse { token }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{auth::encode_token, config::Config, routes::app_router};
+    use axum::{body::Body, http::Request, response::IntoResponse};
+    use sqlx::sqlite::SqlitePoolOptions;
+    use tower::ServiceExt;
+
+    const JWT_SECRET: &str = "test-secret";
+
+    #[test]
+    fn escape_like_pattern_escapes_wildcard_metacharacters() {
+        assert_eq!(escape_like_pattern("john_doe"), "john\\_doe");
+        assert_eq!(escape_like_pattern("100%"), "100\\%");
+        assert_eq!(escape_like_pattern("a\\b"), "a\\\\b");
+        assert_eq!(escape_like_pattern("plain"), "plain");
+    }
+
+    async fn test_state() -> AppState {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+
+        AppState {
+            pool,
+            config: Config {
+                database_url: String::new(),
+                bind_addr: String::new(),
+                jwt_secret: JWT_SECRET.to_string(),
+                cors_allowed_origins: Vec::new(),
+                cors_allowed_methods: Vec::new(),
+                cors_allowed_headers: Vec::new(),
+            },
+        }
+    }
+
+    async fn seed_user(state: &AppState, name: &str, email: &str) {
+        let _ = create_user(
+            State(state.clone()),
+            Json(CreateUser {
+                name: name.to_string(),
+                email: email.to_string(),
+                password: "password123".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn list_users_applies_limit_offset_and_sort() {
+        let state = test_state().await;
+        seed_user(&state, "Charlie", "charlie@example.com").await;
+        seed_user(&state, "Alice", "alice@example.com").await;
+        seed_user(&state, "Bob", "bob@example.com").await;
+
+        let Json(page) = list_users(
+            State(state.clone()),
+            Query(ListUsersQuery {
+                limit: Some(2),
+                offset: Some(0),
+                sort: Some("name".to_string()),
+                email_contains: None,
+            }),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(page.total, 3);
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].name, "Alice");
+        assert_eq!(page.items[1].name, "Bob");
+    }
+
+    #[tokio::test]
+    async fn list_users_filters_by_email_contains() {
+        let state = test_state().await;
+        seed_user(&state, "Alice", "alice@example.com").await;
+        seed_user(&state, "Bob", "bob@other.org").await;
+
+        let Json(page) = list_users(
+            State(state.clone()),
+            Query(ListUsersQuery {
+                limit: None,
+                offset: None,
+                sort: None,
+                email_contains: Some("example.com".to_string()),
+            }),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].name, "Alice");
+    }
+
+    #[tokio::test]
+    async fn list_users_treats_underscore_in_filter_as_a_literal_character() {
+        let state = test_state().await;
+        seed_user(&state, "John Doe", "john_doe@example.com").await;
+        seed_user(&state, "John X Doe", "johnXdoe@example.com").await;
+
+        let Json(page) = list_users(
+            State(state.clone()),
+            Query(ListUsersQuery {
+                limit: None,
+                offset: None,
+                sort: None,
+                email_contains: Some("john_doe".to_string()),
+            }),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].email, "john_doe@example.com");
+    }
+
+    #[tokio::test]
+    async fn list_users_rejects_unknown_sort_column() {
+        let state = test_state().await;
+
+        let err = list_users(
+            State(state.clone()),
+            Query(ListUsersQuery {
+                limit: None,
+                offset: None,
+                sort: Some("password_hash".to_string()),
+                email_contains: None,
+            }),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, Error::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn create_user_rejects_duplicate_email_with_conflict() {
+        let state = test_state().await;
+        seed_user(&state, "Alice", "alice@example.com").await;
+
+        let err = create_user(
+            State(state.clone()),
+            Json(CreateUser {
+                name: "Alice Again".to_string(),
+                email: "alice@example.com".to_string(),
+                password: "password123".to_string(),
+            }),
+        )
+        .await
+        .unwrap_err();
+
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    fn expired_token() -> String {
+        use crate::auth::Claims;
+        use jsonwebtoken::{encode, EncodingKey, Header};
+
+        let claims = Claims {
+            sub: Uuid::new_v4(),
+            exp: 0,
+        };
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(JWT_SECRET.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn update_user_rejects_request_with_no_bearer_token() {
+        let state = test_state().await;
+        let app = app_router(&state.config).with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/users/{}", Uuid::new_v4()))
+                    .header("content-type", "application/json")
+                    .body(Body::from("{}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn update_user_rejects_garbage_bearer_token() {
+        let state = test_state().await;
+        let app = app_router(&state.config).with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/users/{}", Uuid::new_v4()))
+                    .header("content-type", "application/json")
+                    .header("authorization", "Bearer not-a-real-jwt")
+                    .body(Body::from("{}"))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn delete_user_rejects_request_with_no_bearer_token() {
+        let state = test_state().await;
+        let app = app_router(&state.config).with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/users/{}", Uuid::new_v4()))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn delete_user_rejects_expired_bearer_token() {
+        let state = test_state().await;
+        let app = app_router(&state.config).with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/users/{}", Uuid::new_v4()))
+                    .header("authorization", format!("Bearer {}", expired_token()))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn delete_user_accepts_a_valid_bearer_token() {
+        let state = test_state().await;
+        seed_user(&state, "Alice", "alice@example.com").await;
+        let Json(page) = list_users(
+            State(state.clone()),
+            Query(ListUsersQuery {
+                limit: None,
+                offset: None,
+                sort: None,
+                email_contains: None,
+            }),
+        )
+        .await
+        .unwrap();
+        let target_id = page.items[0].id;
+
+        let token = encode_token(Uuid::new_v4(), JWT_SECRET).unwrap();
+        let app = app_router(&state.config).with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/users/{target_id}"))
+                    .header("authorization", format!("Bearer {token}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
 }
@@ -1,9 +1,13 @@
 // src/main.rs
+mod auth;
+mod config;
+mod error;
 mod models;
 mod handlers;
 mod routes;
 
 use axum::Router;
+use config::Config;
 use routes::app_router;
 use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
 use std::net::SocketAddr;
@@ -11,38 +15,27 @@ use std::net::SocketAddr;
 #[derive(Clone)]
 pub struct AppState {
     pub pool: SqlitePool,
+    pub config: Config,
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let db_url = std::env::var("DATABASE_URL")
-        .unwrap_or_else(|_| "sqlite://axum.db".to_string());
+    let config = Config::init();
 
     let pool = SqlitePoolOptions::new()
         .max_connections(5)
-        .connect(&db_url)
+        .connect(&config.database_url)
         .await?;
 
-    // Cria tabela se não existir (sem macros do sqlx)
-    sqlx::query(
-        r#"
-        CREATE TABLE IF NOT EXISTS users (
-            id INTEGER PRIMARY KEY AUTOINCREMENT,
-            name TEXT NOT NULL,
-            email TEXT NOT NULL UNIQUE
-        )
-        "#,
-    )
-    .execute(&pool)
-    .await?;
-
-    let app: Router = app_router().with_state(AppState { pool });
-
-    let addr: SocketAddr = "127.0.0.1:3000".parse().unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await?;
+
+    let addr: SocketAddr = config.bind_addr.parse()?;
+    let app: Router = app_router(&config).with_state(AppState { pool, config });
+
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    println!("→ Server on http://{addr}");
+    tracing::info!("listening on http://{addr}");
     axum::serve(listener, app).await?;
     Ok(())
 }
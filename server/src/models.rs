@@ -1,18 +1,33 @@
 // src/models.rs
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use uuid::Uuid;
 
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 pub struct User {
-    pub id: i64,
+    pub id: Uuid,
     pub name: String,
     pub email: String,
+    #[serde(skip_serializing)]
+    pub password_hash: String,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct CreateUser {
     pub name: String,
     pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoginPayload {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoginResponse {
+    pub token: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -20,3 +35,61 @@ pub struct UpdateUser {
     pub name: Option<String>,
     pub email: Option<String>,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct ListUsersQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub sort: Option<String>,
+    pub email_contains: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserList {
+    pub items: Vec<User>,
+    pub total: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[tokio::test]
+    async fn uuid_migration_backfills_legacy_rows_into_decodable_uuids() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        // Recreate the pre-UUID schema (migration 0001) and seed a row the way it
+        // would have existed before the UUID migration (0002) ever ran.
+        sqlx::query(include_str!("../migrations/0001_create_users.sql"))
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO users (id, name, email, password_hash) VALUES (1, 'Ada', 'ada@example.com', 'hash')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        // Now apply the UUID migration on top of that legacy row.
+        sqlx::query(include_str!("../migrations/0002_users_uuid_id.sql"))
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let user = sqlx::query_as::<_, User>(
+            "SELECT id, name, email, password_hash FROM users WHERE email = ?",
+        )
+        .bind("ada@example.com")
+        .fetch_one(&pool)
+        .await
+        .expect("migrated legacy row should decode into a valid Uuid");
+
+        assert_eq!(user.name, "Ada");
+    }
+}
@@ -1,8 +1,18 @@
-use axum::{routing::get, Router};
-use crate::{handlers, AppState};
+use axum::{
+    http::{HeaderName, HeaderValue, Method},
+    routing::{get, post},
+    Router,
+};
+use tower_http::{
+    cors::{AllowHeaders, AllowMethods, AllowOrigin, CorsLayer},
+    trace::TraceLayer,
+};
 
-pub fn app_router() -> Router<AppState> {
+use crate::{config::Config, handlers, AppState};
+
+pub fn app_router(config: &Config) -> Router<AppState> {
     Router::new()
+        .route("/healthcheck", get(handlers::healthcheck))
         .route(
             "/users",
             get(handlers::list_users).post(handlers::create_user),
@@ -13,4 +23,61 @@ pub fn app_router() -> Router<AppState> {
                 .put(handlers::update_user)
                 .delete(handlers::delete_user),
         )
+        .route("/auth/login", post(handlers::login))
+        .layer(cors_layer(config))
+        .layer(TraceLayer::new_for_http())
+}
+
+/// Builds the CORS policy from `CORS_ALLOWED_ORIGINS`,
+/// `CORS_ALLOWED_METHODS`, and `CORS_ALLOWED_HEADERS`. With no origins
+/// configured this falls back to a permissive policy suitable for local
+/// development, never for production.
+fn cors_layer(config: &Config) -> CorsLayer {
+    if config.cors_allowed_origins.is_empty() {
+        return CorsLayer::permissive();
+    }
+
+    let origins: Vec<HeaderValue> = config
+        .cors_allowed_origins
+        .iter()
+        .filter_map(|origin| origin.parse().ok())
+        .collect();
+
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_methods(allow_methods(config))
+        .allow_headers(allow_headers(config))
+}
+
+/// Parses `CORS_ALLOWED_METHODS`, falling back to a conservative default
+/// set covering the API's own verbs when it isn't configured.
+fn allow_methods(config: &Config) -> AllowMethods {
+    if config.cors_allowed_methods.is_empty() {
+        return AllowMethods::list([Method::GET, Method::POST, Method::PUT, Method::DELETE]);
+    }
+
+    let methods: Vec<Method> = config
+        .cors_allowed_methods
+        .iter()
+        .filter_map(|method| method.parse().ok())
+        .collect();
+    AllowMethods::list(methods)
+}
+
+/// Parses `CORS_ALLOWED_HEADERS`, falling back to a conservative default
+/// set covering what the API itself requires when it isn't configured.
+fn allow_headers(config: &Config) -> AllowHeaders {
+    if config.cors_allowed_headers.is_empty() {
+        return AllowHeaders::list([
+            axum::http::header::CONTENT_TYPE,
+            axum::http::header::AUTHORIZATION,
+        ]);
+    }
+
+    let headers: Vec<HeaderName> = config
+        .cors_allowed_headers
+        .iter()
+        .filter_map(|header| header.parse().ok())
+        .collect();
+    AllowHeaders::list(headers)
 }